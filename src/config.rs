@@ -0,0 +1,226 @@
+use crossterm::style::Color;
+use serde::Deserialize;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// The upstream remote/branch `HomeScreen`'s git check compares `HEAD` against, e.g.
+/// `origin/main`.
+#[derive(Debug, Clone)]
+pub struct Upstream {
+    pub remote: String,
+    pub branch: String,
+    /// Whether `remote`/`branch` came from an explicit `[git]` entry in
+    /// `config.toml`, rather than being the built-in default. `fetch_git_info` only
+    /// overrides `HEAD`'s real tracking branch when this is set.
+    pub configured: bool,
+}
+
+impl Upstream {
+    /// The `<remote>/<branch>` ref `git2` can `revparse`.
+    pub fn git_ref(&self) -> String {
+        format!("{}/{}", self.remote, self.branch)
+    }
+}
+
+impl Default for Upstream {
+    fn default() -> Self {
+        Self {
+            remote: "origin".to_string(),
+            branch: "main".to_string(),
+            configured: false,
+        }
+    }
+}
+
+/// Foreground colors for the accents that used to be hardcoded `Color::Green`.
+#[derive(Debug, Clone, Copy)]
+pub struct Theme {
+    pub footer: Color,
+    pub status: Color,
+    pub header: Color,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            footer: Color::Green,
+            status: Color::Green,
+            header: Color::Yellow,
+        }
+    }
+}
+
+/// User config, loaded once at startup from the platform config dir and threaded
+/// into the screens that used to hardcode these values.
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub upstream: Upstream,
+    pub min_width: u16,
+    pub min_height: u16,
+    pub auto_refresh_interval: Duration,
+    pub theme: Theme,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            upstream: Upstream::default(),
+            min_width: 80,
+            min_height: 24,
+            auto_refresh_interval: Duration::from_secs(30),
+            theme: Theme::default(),
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct RawConfig {
+    git: Option<RawGit>,
+    window: Option<RawWindow>,
+    theme: Option<RawTheme>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct RawGit {
+    remote: Option<String>,
+    branch: Option<String>,
+    auto_refresh_secs: Option<u64>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct RawWindow {
+    min_width: Option<u16>,
+    min_height: Option<u16>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct RawTheme {
+    footer: Option<String>,
+    status: Option<String>,
+    header: Option<String>,
+}
+
+impl Config {
+    /// Reads `<config dir>/bibeln/config.toml` (`~/.config/bibeln/config.toml` on
+    /// Linux), falling back to defaults for anything missing or if the file doesn't
+    /// exist. Returns the all-default `Config` alongside a parse-error message if the
+    /// file exists but isn't valid TOML, so the caller can surface it once a screen
+    /// (and its `MiniBuffer`) is available instead of discarding it.
+    pub fn load() -> (Self, Option<String>) {
+        let Some(path) = config_path() else {
+            return (Self::from_raw(RawConfig::default()), None);
+        };
+        let Ok(contents) = std::fs::read_to_string(&path) else {
+            return (Self::from_raw(RawConfig::default()), None);
+        };
+        match toml::from_str(&contents) {
+            Ok(raw) => (Self::from_raw(raw), None),
+            Err(e) => (
+                Self::default(),
+                Some(format!("Failed to parse {}: {e}", path.display())),
+            ),
+        }
+    }
+
+    fn from_raw(raw: RawConfig) -> Self {
+        let defaults = Self::default();
+
+        let (upstream, auto_refresh_interval) = match raw.git {
+            Some(git) => {
+                let configured = git.remote.is_some() || git.branch.is_some();
+                (
+                    Upstream {
+                        remote: git.remote.unwrap_or(defaults.upstream.remote),
+                        branch: git.branch.unwrap_or(defaults.upstream.branch),
+                        configured,
+                    },
+                    git.auto_refresh_secs
+                        .map(Duration::from_secs)
+                        .unwrap_or(defaults.auto_refresh_interval),
+                )
+            }
+            None => (defaults.upstream, defaults.auto_refresh_interval),
+        };
+
+        let (min_width, min_height) = raw
+            .window
+            .map(|window| {
+                (
+                    window.min_width.unwrap_or(defaults.min_width),
+                    window.min_height.unwrap_or(defaults.min_height),
+                )
+            })
+            .unwrap_or((defaults.min_width, defaults.min_height));
+
+        let theme = raw
+            .theme
+            .map(|theme| Theme {
+                footer: theme
+                    .footer
+                    .as_deref()
+                    .and_then(parse_color)
+                    .unwrap_or(defaults.theme.footer),
+                status: theme
+                    .status
+                    .as_deref()
+                    .and_then(parse_color)
+                    .unwrap_or(defaults.theme.status),
+                header: theme
+                    .header
+                    .as_deref()
+                    .and_then(parse_color)
+                    .unwrap_or(defaults.theme.header),
+            })
+            .unwrap_or(defaults.theme);
+
+        Self {
+            upstream,
+            min_width,
+            min_height,
+            auto_refresh_interval,
+            theme,
+        }
+    }
+}
+
+fn config_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("bibeln").join("config.toml"))
+}
+
+/// Parses a theme color as a named color or `#rrggbb` hex, as
+/// git-interactive-rebase-tool does with its own theme file.
+fn parse_color(s: &str) -> Option<Color> {
+    if let Some(hex) = s.strip_prefix('#') {
+        return parse_hex(hex);
+    }
+
+    Some(match s.to_ascii_lowercase().as_str() {
+        "black" => Color::Black,
+        "darkgrey" | "darkgray" => Color::DarkGrey,
+        "red" => Color::Red,
+        "darkred" => Color::DarkRed,
+        "green" => Color::Green,
+        "darkgreen" => Color::DarkGreen,
+        "yellow" => Color::Yellow,
+        "darkyellow" => Color::DarkYellow,
+        "blue" => Color::Blue,
+        "darkblue" => Color::DarkBlue,
+        "magenta" => Color::Magenta,
+        "darkmagenta" => Color::DarkMagenta,
+        "cyan" => Color::Cyan,
+        "darkcyan" => Color::DarkCyan,
+        "white" => Color::White,
+        "grey" | "gray" => Color::Grey,
+        _ => return None,
+    })
+}
+
+fn parse_hex(hex: &str) -> Option<Color> {
+    if hex.len() != 6 {
+        return None;
+    }
+    let r = u8::from_str_radix(&hex[0..2], 16).ok()?;
+    let g = u8::from_str_radix(&hex[2..4], 16).ok()?;
+    let b = u8::from_str_radix(&hex[4..6], 16).ok()?;
+    Some(Color::Rgb { r, g, b })
+}
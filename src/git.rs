@@ -0,0 +1,151 @@
+use git2::{Reference, Repository};
+use std::sync::mpsc;
+use std::thread;
+
+use crate::config::Upstream;
+use crate::minibuffer::MessageType;
+
+/// Result of comparing `HEAD` against its resolved upstream — normally `HEAD`'s real
+/// git-configured tracking branch, or `Config`'s `git.remote`/`git.branch`
+/// (`origin/main` by default) when no tracking branch is set or the user has
+/// explicitly configured one — computed on a background thread via libgit2.
+#[derive(Debug, Clone)]
+pub enum GitStatus {
+    UpToDate,
+    Ahead(u32),
+    Behind(u32),
+    Diverged { ahead: u32, behind: u32 },
+    NoRepository,
+    NoUpstream,
+    DetachedHead,
+    CompareFailed(String),
+}
+
+impl GitStatus {
+    /// The minibuffer severity and text this status should be reported with.
+    pub fn message(&self) -> (MessageType, String) {
+        match self {
+            GitStatus::UpToDate => (
+                MessageType::Note,
+                "You are up to date with upstream".to_string(),
+            ),
+            GitStatus::Ahead(n) => (
+                MessageType::Note,
+                format!("You are ahead by {} commit(s)", n),
+            ),
+            GitStatus::Behind(n) => (
+                MessageType::Note,
+                format!("You are behind by {} commit(s)", n),
+            ),
+            GitStatus::Diverged { ahead, behind } => (
+                MessageType::Note,
+                format!(
+                    "Diverged: ahead by {} and behind by {} commit(s)",
+                    ahead, behind
+                ),
+            ),
+            GitStatus::NoRepository => (
+                MessageType::Error,
+                "Not inside a git repository".to_string(),
+            ),
+            GitStatus::NoUpstream => (
+                MessageType::Error,
+                "Configured upstream not found".to_string(),
+            ),
+            GitStatus::DetachedHead => (MessageType::Error, "HEAD is detached".to_string()),
+            GitStatus::CompareFailed(e) => (
+                MessageType::Error,
+                format!("Could not compare with upstream: {}", e),
+            ),
+        }
+    }
+}
+
+/// Best-effort `git fetch` of the configured upstream remote. Failures (no remote,
+/// no network, auth required) are swallowed, same as the old `git fetch --quiet` did.
+fn fetch_remote(repo: &Repository, remote_name: &str) -> std::result::Result<(), git2::Error> {
+    let mut remote = repo.find_remote(remote_name)?;
+    remote.fetch(&[] as &[&str], None, None)
+}
+
+/// Resolves the commit to compare `HEAD` against: `HEAD`'s real git-configured
+/// tracking branch (via `branch_upstream_name`) unless the user has explicitly set
+/// `[git] remote`/`branch` in `config.toml`, in which case that configured ref wins.
+/// Also falls back to the configured ref if `HEAD` has no tracking branch set.
+fn resolve_upstream_oid(
+    repo: &Repository,
+    head: &Reference,
+    upstream: &Upstream,
+) -> Option<git2::Oid> {
+    if !upstream.configured {
+        if let Some(tracking) = head
+            .name()
+            .and_then(|name| repo.branch_upstream_name(name).ok())
+        {
+            if let Some(commit) = tracking
+                .as_str()
+                .and_then(|name| repo.revparse_single(name).ok())
+                .and_then(|obj| obj.peel_to_commit().ok())
+            {
+                return Some(commit.id());
+            }
+        }
+    }
+
+    repo.revparse_single(&upstream.git_ref())
+        .ok()
+        .and_then(|obj| obj.peel_to_commit().ok())
+        .map(|commit| commit.id())
+}
+
+/// Opens the repo, resolves `HEAD` and computes its ahead/behind graph distance
+/// against its resolved upstream (see `resolve_upstream_oid`). Blocks on I/O, so it
+/// must only ever run on the background git thread.
+pub fn fetch_git_info(upstream: &Upstream) -> GitStatus {
+    let repo = match Repository::discover(".") {
+        Ok(repo) => repo,
+        Err(_) => return GitStatus::NoRepository,
+    };
+
+    let _ = fetch_remote(&repo, &upstream.remote);
+
+    let head = match repo.head() {
+        Ok(head) => head,
+        Err(_) => return GitStatus::DetachedHead,
+    };
+
+    if !head.is_branch() {
+        return GitStatus::DetachedHead;
+    }
+
+    let local_oid = match head.target() {
+        Some(oid) => oid,
+        None => return GitStatus::DetachedHead,
+    };
+
+    let upstream_oid = match resolve_upstream_oid(&repo, &head, upstream) {
+        Some(oid) => oid,
+        None => return GitStatus::NoUpstream,
+    };
+
+    match repo.graph_ahead_behind(local_oid, upstream_oid) {
+        Ok((ahead, behind)) => match (ahead as u32, behind as u32) {
+            (0, 0) => GitStatus::UpToDate,
+            (ahead, 0) => GitStatus::Ahead(ahead),
+            (0, behind) => GitStatus::Behind(behind),
+            (ahead, behind) => GitStatus::Diverged { ahead, behind },
+        },
+        Err(e) => GitStatus::CompareFailed(e.to_string()),
+    }
+}
+
+/// Spawns a one-shot worker that computes `GitStatus` against `upstream` and sends
+/// it back over `tx`. Callers should show a "checking..." indicator immediately,
+/// then redraw once the message arrives instead of blocking the event loop on the
+/// fetch.
+pub fn spawn_git_check(tx: mpsc::Sender<GitStatus>, upstream: Upstream) {
+    thread::spawn(move || {
+        let info = fetch_git_info(&upstream);
+        let _ = tx.send(info);
+    });
+}
@@ -0,0 +1,113 @@
+use crossterm::{
+    cursor,
+    event::{KeyCode, KeyEvent},
+    style::{ResetColor, SetForegroundColor},
+    terminal::{self, ClearType},
+    ExecutableCommand,
+};
+use std::io::{Result, Stdout, Write};
+use std::sync::mpsc::{self, Receiver};
+use std::time::{Duration, Instant};
+
+use crate::config::{Config, Theme, Upstream};
+use crate::git::{self, GitStatus};
+use crate::minibuffer::{MessageType, MiniBuffer};
+use crate::screen::{Action, Screen, ScreenId};
+
+const ASCII_ART: &str = r#"
+| |   (_) |        | |
+| |__  _| |__   ___| |_ __
+| '_ \| | '_ \ / _ \ | '_ \
+| |_) | | |_) |  __/ | | | |
+|_.__/|_|_.__/ \___|_|_| |_|
+"#;
+
+/// The ASCII-art landing screen. Owns the background git-status channel and shows a
+/// "checking..." indicator immediately on `c`, then the resolved status once it
+/// arrives, without blocking the event loop on the fetch.
+pub struct HomeScreen {
+    git_tx: mpsc::Sender<GitStatus>,
+    git_rx: Receiver<GitStatus>,
+    minibuffer: MiniBuffer,
+    last_auto_refresh: Instant,
+    upstream: Upstream,
+    auto_refresh_interval: Duration,
+    theme: Theme,
+}
+
+impl HomeScreen {
+    pub fn new(config: &Config) -> Self {
+        let (git_tx, git_rx) = mpsc::channel();
+        Self {
+            git_tx,
+            git_rx,
+            minibuffer: MiniBuffer::new(config.theme),
+            last_auto_refresh: Instant::now(),
+            upstream: config.upstream.clone(),
+            auto_refresh_interval: config.auto_refresh_interval,
+            theme: config.theme,
+        }
+    }
+
+    /// Surfaces a config-load failure (e.g. a malformed `config.toml`) on the
+    /// minibuffer instead of letting it fail silently, since defaults are already in
+    /// effect by the time this screen exists.
+    pub fn show_config_error(&mut self, message: String) {
+        self.minibuffer.set(MessageType::Error, message);
+    }
+
+    /// Polls the background channel and kicks off an auto-refresh if the interval has
+    /// elapsed. Returns `true` if the status line changed and a redraw is needed.
+    pub fn tick(&mut self) -> bool {
+        if self.last_auto_refresh.elapsed() >= self.auto_refresh_interval {
+            self.last_auto_refresh = Instant::now();
+            git::spawn_git_check(self.git_tx.clone(), self.upstream.clone());
+        }
+
+        if let Ok(info) = self.git_rx.try_recv() {
+            let (kind, text) = info.message();
+            self.minibuffer.set(kind, text);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl Screen for HomeScreen {
+    fn draw(&mut self, stdout: &mut Stdout, cols: u16, rows: u16) -> Result<()> {
+        stdout.execute(terminal::Clear(ClearType::All))?;
+
+        let lines: Vec<&str> = ASCII_ART.trim_matches('\n').lines().collect();
+        for (i, line) in lines.iter().enumerate() {
+            let col = (cols.saturating_sub(line.len() as u16)) / 2;
+            stdout.execute(cursor::MoveTo(col, 2 + i as u16))?;
+            writeln!(stdout, "{}", line)?;
+        }
+
+        let footer = "[q]uit - [c]heck - [s]tatus";
+        let col = (cols.saturating_sub(footer.len() as u16)) / 2;
+        let footer_row = 2 + lines.len() as u16 + 1;
+        stdout.execute(cursor::MoveTo(col, footer_row))?;
+        stdout.execute(SetForegroundColor(self.theme.footer))?;
+        writeln!(stdout, "{}", footer)?;
+        stdout.execute(ResetColor)?;
+        stdout.flush()?;
+
+        self.minibuffer.draw(stdout, cols, rows)
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> Action {
+        match key.code {
+            KeyCode::Char('q') => Action::Quit,
+            KeyCode::Char('c') => {
+                self.minibuffer.set(MessageType::Note, "checking...");
+                git::spawn_git_check(self.git_tx.clone(), self.upstream.clone());
+                self.last_auto_refresh = Instant::now();
+                Action::None
+            }
+            KeyCode::Char('s') => Action::SwitchTo(ScreenId::Status),
+            _ => Action::None,
+        }
+    }
+}
@@ -1,92 +1,92 @@
 use crossterm::{
     cursor::{self, Hide, Show},
-    event::{self, Event, KeyCode},
+    event::{self, Event},
     execute,
-    style::{SetForegroundColor, ResetColor, Color},
     terminal::{self, ClearType, EnterAlternateScreen, LeaveAlternateScreen},
     ExecutableCommand,
 };
-use std::io::{stdout, Write, Result};
-use std::process::Command;
+use std::io::{stdout, Result, Write};
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 
-const MIN_WIDTH: u16 = 80;
-const MIN_HEIGHT: u16 = 24;
+mod config;
+mod git;
+mod home;
+mod minibuffer;
+mod rebase;
+mod screen;
+mod status;
+mod widgets;
 
-const ASCII_ART: &str = r#"
-| |   (_) |        | |      
-| |__  _| |__   ___| |_ __  
-| '_ \| | '_ \ / _ \ | '_ \ 
-| |_) | | |_) |  __/ | | | |
-|_.__/|_|_.__/ \___|_|_| |_|
-"#;
+use config::Config;
+use home::HomeScreen;
+use screen::{Action, Screen, ScreenId};
+use status::StatusScreen;
 
-fn draw(stdout: &mut std::io::Stdout, cols: u16, rows: u16) -> Result<()> {
-    stdout.execute(terminal::Clear(ClearType::All))?;
+/// Default location git leaves the todo file at during an interactive rebase.
+const REBASE_TODO_PATH: &str = ".git/rebase-merge/git-rebase-todo";
 
-    if cols < MIN_WIDTH || rows < MIN_HEIGHT {
-        let message = format!(
-            "Window too small.\nMinimum: {}x{}\nCurrent: {}x{}",
-            MIN_WIDTH, MIN_HEIGHT, cols, rows
-        );
-        let lines: Vec<&str> = message.lines().collect();
-        let start_row = (rows / 2).saturating_sub(lines.len() as u16 / 2);
-        for (i, line) in lines.iter().enumerate() {
-            let col = (cols.saturating_sub(line.len() as u16)) / 2;
-            stdout.execute(cursor::MoveTo(col, start_row + i as u16))?;
-            writeln!(stdout, "{}", line)?;
-        }
-    } else {
-        let lines: Vec<&str> = ASCII_ART.trim_matches('\n').lines().collect();
-        for (i, line) in lines.iter().enumerate() {
-            let col = (cols.saturating_sub(line.len() as u16)) / 2;
-            stdout.execute(cursor::MoveTo(col, 2 + i as u16))?;
-            writeln!(stdout, "{}", line)?;
-        }
-        let footer = "[q]uit - [c]heck";
-        let col = (cols.saturating_sub(footer.len() as u16)) / 2;
-        let row = 2 + lines.len() as u16 + 1;
-        stdout.execute(cursor::MoveTo(col, row))?;
-        stdout.execute(SetForegroundColor(Color::Green))?;
-        writeln!(stdout, "{}", footer)?;
-        stdout.execute(ResetColor)?;
+/// Resolves which `git-rebase-todo` file to edit: an explicit argv path (as git's
+/// `sequence.editor` passes), or the conventional in-progress rebase location.
+fn rebase_todo_path() -> Option<PathBuf> {
+    if let Some(arg) = std::env::args().nth(1) {
+        return Some(PathBuf::from(arg));
     }
+    let default_path = Path::new(REBASE_TODO_PATH);
+    default_path.exists().then(|| default_path.to_path_buf())
+}
 
+fn draw_too_small(
+    stdout: &mut std::io::Stdout,
+    cols: u16,
+    rows: u16,
+    config: &Config,
+) -> Result<()> {
+    stdout.execute(terminal::Clear(ClearType::All))?;
+    let message = format!(
+        "Window too small.\nMinimum: {}x{}\nCurrent: {}x{}",
+        config.min_width, config.min_height, cols, rows
+    );
+    let lines: Vec<&str> = message.lines().collect();
+    let start_row = (rows / 2).saturating_sub(lines.len() as u16 / 2);
+    for (i, line) in lines.iter().enumerate() {
+        let col = (cols.saturating_sub(line.len() as u16)) / 2;
+        stdout.execute(cursor::MoveTo(col, start_row + i as u16))?;
+        writeln!(stdout, "{}", line)?;
+    }
     stdout.flush()?;
     Ok(())
 }
 
-fn check_git_status() -> String {
-    let _ = Command::new("git")
-        .args(&["fetch", "--quiet"])
-        .output();
-
-    let ahead = Command::new("git")
-        .args(&["rev-list", "--count", "origin/main..HEAD"])
-        .output()
-        .ok()
-        .and_then(|o| String::from_utf8(o.stdout).ok())
-        .and_then(|s| s.trim().parse::<u32>().ok())
-        .unwrap_or(0);
-
-    let behind = Command::new("git")
-        .args(&["rev-list", "--count", "HEAD..origin/main"])
-        .output()
-        .ok()
-        .and_then(|o| String::from_utf8(o.stdout).ok())
-        .and_then(|s| s.trim().parse::<u32>().ok())
-        .unwrap_or(0);
-
-    if ahead > 0 {
-        format!("[info] You are ahead by {} commit(s)", ahead)
-    } else if behind > 0 {
-        format!("[info] You are behind by {} commit(s)", behind)
-    } else {
-        "[info] You are up to date with upstream".to_string()
+/// Dispatches to the active screen's `draw()`, or the too-small message if the
+/// terminal doesn't meet the minimum size.
+fn redraw(
+    current: ScreenId,
+    home_screen: &mut HomeScreen,
+    status_screen: &mut Option<StatusScreen>,
+    stdout: &mut std::io::Stdout,
+    cols: u16,
+    rows: u16,
+    config: &Config,
+) -> Result<()> {
+    if cols < config.min_width || rows < config.min_height {
+        return draw_too_small(stdout, cols, rows, config);
+    }
+    match current {
+        ScreenId::Home => home_screen.draw(stdout, cols, rows),
+        ScreenId::Status => status_screen
+            .get_or_insert_with(|| StatusScreen::new(config))
+            .draw(stdout, cols, rows),
     }
 }
 
 fn main() -> Result<()> {
+    let (config, config_error) = Config::load();
+
+    if let Some(path) = rebase_todo_path() {
+        return rebase::run(&path, config.theme);
+    }
+
     let mut stdout = stdout();
 
     execute!(stdout, EnterAlternateScreen)?;
@@ -94,26 +94,55 @@ fn main() -> Result<()> {
     stdout.execute(Hide)?;
 
     let mut last_size = terminal::size()?;
-    draw(&mut stdout, last_size.0, last_size.1)?;
+
+    let mut home_screen = HomeScreen::new(&config);
+    if let Some(error) = config_error {
+        home_screen.show_config_error(error);
+    }
+    let mut status_screen: Option<StatusScreen> = None;
+    let mut current = ScreenId::Home;
+
+    redraw(
+        current,
+        &mut home_screen,
+        &mut status_screen,
+        &mut stdout,
+        last_size.0,
+        last_size.1,
+        &config,
+    )?;
 
     loop {
+        let mut needs_redraw = false;
+
         if event::poll(Duration::from_millis(100))? {
             match event::read()? {
-                Event::Key(key_event) if key_event.code == KeyCode::Char('q') => break,
-                Event::Key(key_event) if key_event.code == KeyCode::Char('c') => {
-                    let size = terminal::size()?;
-                    let status = check_git_status();
-                    let col = (size.0.saturating_sub(status.len() as u16)) / 2;
-                    let row = 2 + ASCII_ART.trim_matches('\n').lines().count() as u16 + 2;
-                    stdout.execute(cursor::MoveTo(col, row))?;
-                    stdout.execute(SetForegroundColor(Color::Green))?;
-                    writeln!(stdout, "{}", status)?;
-                    stdout.execute(ResetColor)?;
-                    stdout.flush()?;
+                Event::Key(key_event) => {
+                    let action = match current {
+                        ScreenId::Home => home_screen.handle_key(key_event),
+                        ScreenId::Status => status_screen
+                            .get_or_insert_with(|| StatusScreen::new(&config))
+                            .handle_key(key_event),
+                    };
+                    match action {
+                        Action::None => needs_redraw = true,
+                        Action::Quit => break,
+                        Action::SwitchTo(ScreenId::Status) => {
+                            status_screen
+                                .get_or_insert_with(|| StatusScreen::new(&config))
+                                .refresh();
+                            current = ScreenId::Status;
+                            needs_redraw = true;
+                        }
+                        Action::SwitchTo(next) => {
+                            current = next;
+                            needs_redraw = true;
+                        }
+                    }
                 }
                 Event::Resize(cols, rows) => {
                     last_size = (cols, rows);
-                    draw(&mut stdout, cols, rows)?;
+                    needs_redraw = true;
                 }
                 _ => {}
             }
@@ -121,9 +150,25 @@ fn main() -> Result<()> {
             let size = terminal::size()?;
             if size != last_size {
                 last_size = size;
-                draw(&mut stdout, size.0, size.1)?;
+                needs_redraw = true;
             }
         }
+
+        if current == ScreenId::Home && home_screen.tick() {
+            needs_redraw = true;
+        }
+
+        if needs_redraw {
+            redraw(
+                current,
+                &mut home_screen,
+                &mut status_screen,
+                &mut stdout,
+                last_size.0,
+                last_size.1,
+                &config,
+            )?;
+        }
     }
 
     terminal::disable_raw_mode()?;
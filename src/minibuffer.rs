@@ -0,0 +1,122 @@
+use crossterm::{
+    cursor, queue,
+    style::{Color, Print, ResetColor, SetForegroundColor},
+    terminal::{self, ClearType},
+};
+use std::io::{Result, Stdout, Write};
+
+use crate::config::Theme;
+
+/// Severity of a message shown in the minibuffer, controlling its color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageType {
+    Note,
+    Error,
+}
+
+impl MessageType {
+    fn color(self, theme: &Theme) -> Color {
+        match self {
+            MessageType::Note => theme.status,
+            MessageType::Error => Color::Red,
+        }
+    }
+}
+
+/// Owns the bottom line(s) of the terminal: transient status messages, and blocking
+/// prompts for confirmations or free text, in the spirit of gex's minibuffer.
+pub struct MiniBuffer {
+    message: Option<(MessageType, String)>,
+    theme: Theme,
+}
+
+impl MiniBuffer {
+    pub fn new(theme: Theme) -> Self {
+        Self {
+            message: None,
+            theme,
+        }
+    }
+
+    pub fn set(&mut self, kind: MessageType, text: impl Into<String>) {
+        self.message = Some((kind, text.into()));
+    }
+
+    pub fn clear(&mut self) {
+        self.message = None;
+    }
+
+    pub fn has_message(&self) -> bool {
+        self.message.is_some()
+    }
+
+    /// Renders the current message word-wrapped to `cols`, anchored to the bottom of
+    /// the `rows`-tall terminal so it never corrupts the rest of the layout.
+    pub fn draw(&self, stdout: &mut Stdout, cols: u16, rows: u16) -> Result<()> {
+        let Some((kind, text)) = &self.message else {
+            return Ok(());
+        };
+
+        let wrapped = word_wrap(text, cols as usize);
+        let start_row = rows.saturating_sub(wrapped.len() as u16);
+        for (i, line) in wrapped.iter().enumerate() {
+            queue!(
+                stdout,
+                cursor::MoveTo(0, start_row + i as u16),
+                terminal::Clear(ClearType::CurrentLine),
+                SetForegroundColor(kind.color(&self.theme)),
+                Print(line),
+                ResetColor
+            )?;
+        }
+        stdout.flush()?;
+        Ok(())
+    }
+
+}
+
+/// Greedily word-wraps `text` to `width` columns. Falls back to hard character
+/// breaks for single words longer than `width`.
+fn word_wrap(text: &str, width: usize) -> Vec<String> {
+    if width == 0 {
+        return vec![text.to_string()];
+    }
+
+    let mut lines = Vec::new();
+    let mut current = String::new();
+
+    for word in text.split_whitespace() {
+        let candidate_len = if current.is_empty() {
+            word.len()
+        } else {
+            current.len() + 1 + word.len()
+        };
+
+        if candidate_len > width && !current.is_empty() {
+            lines.push(std::mem::take(&mut current));
+        }
+
+        if word.len() > width {
+            if !current.is_empty() {
+                lines.push(std::mem::take(&mut current));
+            }
+            for chunk in word.as_bytes().chunks(width) {
+                lines.push(String::from_utf8_lossy(chunk).into_owned());
+            }
+            continue;
+        }
+
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(word);
+    }
+
+    if !current.is_empty() {
+        lines.push(current);
+    }
+    if lines.is_empty() {
+        lines.push(String::new());
+    }
+    lines
+}
@@ -0,0 +1,274 @@
+use crossterm::{
+    cursor::{Hide, Show},
+    event::{self, Event, KeyCode},
+    execute,
+    style::Color,
+    terminal::{self, EnterAlternateScreen, LeaveAlternateScreen},
+    ExecutableCommand,
+};
+use std::fs;
+use std::io::{stdout, Result, Write};
+use std::path::Path;
+use std::process;
+
+use crate::config::Theme;
+use crate::widgets::{draw_list, Row, ScrollList};
+
+/// One of the actions understood by `git rebase --interactive`'s todo list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Action {
+    Pick,
+    Reword,
+    Edit,
+    Squash,
+    Fixup,
+    Drop,
+}
+
+impl Action {
+    fn token(self) -> &'static str {
+        match self {
+            Action::Pick => "pick",
+            Action::Reword => "reword",
+            Action::Edit => "edit",
+            Action::Squash => "squash",
+            Action::Fixup => "fixup",
+            Action::Drop => "drop",
+        }
+    }
+
+    fn from_token(token: &str) -> Option<Action> {
+        match token {
+            "pick" | "p" => Some(Action::Pick),
+            "reword" | "r" => Some(Action::Reword),
+            "edit" | "e" => Some(Action::Edit),
+            "squash" | "s" => Some(Action::Squash),
+            "fixup" | "f" => Some(Action::Fixup),
+            "drop" | "d" => Some(Action::Drop),
+            _ => None,
+        }
+    }
+}
+
+/// One line of the todo file: either a parsed `pick`/`reword`/... command or a
+/// comment/blank line preserved verbatim.
+#[derive(Debug, Clone)]
+enum Line {
+    Todo {
+        action: Action,
+        hash: String,
+        rest: String,
+    },
+    Verbatim(String),
+}
+
+impl Line {
+    fn parse(raw: &str) -> Line {
+        if raw.starts_with('#') || raw.trim().is_empty() {
+            return Line::Verbatim(raw.to_string());
+        }
+
+        let mut parts = raw.splitn(3, ' ');
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some(token), Some(hash), rest) => match Action::from_token(token) {
+                Some(action) => Line::Todo {
+                    action,
+                    hash: hash.to_string(),
+                    rest: rest.unwrap_or("").to_string(),
+                },
+                None => Line::Verbatim(raw.to_string()),
+            },
+            _ => Line::Verbatim(raw.to_string()),
+        }
+    }
+
+    fn render(&self) -> String {
+        match self {
+            Line::Todo { action, hash, rest } if rest.is_empty() => {
+                format!("{} {}", action.token(), hash)
+            }
+            Line::Todo { action, hash, rest } => format!("{} {} {}", action.token(), hash, rest),
+            Line::Verbatim(raw) => raw.clone(),
+        }
+    }
+}
+
+/// Reads and parses a `git-rebase-todo` file into editable lines.
+fn load(path: &Path) -> Result<Vec<Line>> {
+    let content = fs::read_to_string(path)?;
+    Ok(content.lines().map(Line::parse).collect())
+}
+
+/// Serializes the edited lines back to the todo file's on-disk format.
+fn serialize(lines: &[Line]) -> String {
+    let mut out = String::new();
+    for line in lines {
+        out.push_str(&line.render());
+        out.push('\n');
+    }
+    out
+}
+
+/// Maps parsed todo lines onto the shared scrollable list widget's rows, coloring
+/// `Todo` lines green to set them apart from preserved comments.
+fn todo_rows(lines: &[Line]) -> Vec<Row> {
+    lines
+        .iter()
+        .map(|line| Row::Entry {
+            label: line.render(),
+            path: None,
+            color: matches!(line, Line::Todo { .. }).then_some(Color::Green),
+        })
+        .collect()
+}
+
+/// Runs the interactive rebase todo editor against `path`, blocking until the user
+/// writes (`w`) or aborts (`q`). Exits the process directly so the exit code reaches
+/// git's `sequence.editor` caller: 0 to proceed with the rebase, non-zero to cancel it.
+pub fn run(path: &Path, theme: Theme) -> Result<()> {
+    let mut lines = load(path)?;
+    let mut selected = 0usize;
+    let mut scroll = ScrollList::new();
+
+    let mut stdout = stdout();
+    execute!(stdout, EnterAlternateScreen)?;
+    terminal::enable_raw_mode()?;
+    stdout.execute(Hide)?;
+
+    let redraw = |stdout: &mut std::io::Stdout,
+                  lines: &[Line],
+                  selected: usize,
+                  scroll: &mut ScrollList|
+     -> Result<()> {
+        stdout.execute(terminal::Clear(terminal::ClearType::All))?;
+        let (_, rows) = terminal::size()?;
+        let rendered_rows = todo_rows(lines);
+        scroll.update_offset(selected, rendered_rows.len(), rows as usize);
+        draw_list(
+            stdout,
+            &rendered_rows,
+            scroll.offset,
+            selected,
+            0,
+            rows,
+            theme.header,
+        )
+    };
+
+    redraw(&mut stdout, &lines, selected, &mut scroll)?;
+
+    let exit_code = loop {
+        if let Event::Key(key_event) = event::read()? {
+            match key_event.code {
+                KeyCode::Char('j') | KeyCode::Down if selected + 1 < lines.len() => {
+                    selected += 1;
+                }
+                KeyCode::Char('k') | KeyCode::Up => {
+                    selected = selected.saturating_sub(1);
+                }
+                KeyCode::Char('J') if selected + 1 < lines.len() => {
+                    lines.swap(selected, selected + 1);
+                    selected += 1;
+                }
+                KeyCode::Char('K') if selected > 0 => {
+                    lines.swap(selected, selected - 1);
+                    selected -= 1;
+                }
+                KeyCode::Char('w') => {
+                    fs::write(path, serialize(&lines))?;
+                    break 0;
+                }
+                KeyCode::Char('q') => break 1,
+                KeyCode::Char(c) => {
+                    if let (Some(new_action), Some(Line::Todo { action, .. })) =
+                        (Action::from_token(&c.to_string()), lines.get_mut(selected))
+                    {
+                        *action = new_action;
+                    }
+                }
+                _ => {}
+            }
+            redraw(&mut stdout, &lines, selected, &mut scroll)?;
+        }
+    };
+
+    terminal::disable_raw_mode()?;
+    stdout.execute(Show)?;
+    execute!(stdout, LeaveAlternateScreen)?;
+    stdout.flush()?;
+
+    process::exit(exit_code);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render_parsed(raw: &str) -> String {
+        Line::parse(raw).render()
+    }
+
+    #[test]
+    fn round_trips_full_action_tokens() {
+        for (token, hash) in [
+            ("pick", "abc1234"),
+            ("reword", "abc1234"),
+            ("edit", "abc1234"),
+            ("squash", "abc1234"),
+            ("fixup", "abc1234"),
+            ("drop", "abc1234"),
+        ] {
+            let raw = format!("{token} {hash} commit subject");
+            assert_eq!(render_parsed(&raw), raw);
+        }
+    }
+
+    #[test]
+    fn round_trips_short_action_tokens() {
+        let raw = "p abc1234 commit subject";
+        match Line::parse(raw) {
+            Line::Todo { action, hash, rest } => {
+                assert_eq!(action, Action::Pick);
+                assert_eq!(hash, "abc1234");
+                assert_eq!(rest, "commit subject");
+            }
+            other => panic!("expected a Todo line, got {other:?}"),
+        }
+        // Short forms render back out using the canonical long token.
+        assert_eq!(render_parsed(raw), "pick abc1234 commit subject");
+    }
+
+    #[test]
+    fn preserves_comments_and_blank_lines_verbatim() {
+        for raw in ["# Rebase abc1234..def5678 onto abc1234", "", "   "] {
+            assert_eq!(render_parsed(raw), raw);
+        }
+    }
+
+    #[test]
+    fn round_trips_todo_line_with_no_rest() {
+        let raw = "pick abc1234";
+        match Line::parse(raw) {
+            Line::Todo { action, hash, rest } => {
+                assert_eq!(action, Action::Pick);
+                assert_eq!(hash, "abc1234");
+                assert_eq!(rest, "");
+            }
+            other => panic!("expected a Todo line, got {other:?}"),
+        }
+        assert_eq!(render_parsed(raw), raw);
+    }
+
+    #[test]
+    fn serialize_joins_rendered_lines_with_newlines() {
+        let lines = vec![
+            Line::parse("pick abc1234 first commit"),
+            Line::parse("# a comment"),
+            Line::parse("squash def5678 second commit"),
+        ];
+        assert_eq!(
+            serialize(&lines),
+            "pick abc1234 first commit\n# a comment\nsquash def5678 second commit\n"
+        );
+    }
+}
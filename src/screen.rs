@@ -0,0 +1,23 @@
+use crossterm::event::KeyEvent;
+use std::io::{Result, Stdout};
+
+/// Identifies one of the screens `main` can switch between.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScreenId {
+    Home,
+    Status,
+}
+
+/// What the event loop should do after a screen has handled a key press.
+pub enum Action {
+    None,
+    Quit,
+    SwitchTo(ScreenId),
+}
+
+/// Implemented by each full-screen view so `main` can draw and dispatch keys without
+/// knowing the concrete screen it's showing.
+pub trait Screen {
+    fn draw(&mut self, stdout: &mut Stdout, cols: u16, rows: u16) -> Result<()>;
+    fn handle_key(&mut self, key: KeyEvent) -> Action;
+}
@@ -0,0 +1,260 @@
+use crossterm::{
+    cursor,
+    event::{KeyCode, KeyEvent},
+    terminal::{self, ClearType},
+    ExecutableCommand,
+};
+use git2::{Repository, StatusOptions};
+use std::io::{Result, Stdout, Write};
+use std::path::PathBuf;
+
+use crate::config::{Config, Theme};
+use crate::minibuffer::{MessageType, MiniBuffer};
+use crate::screen::{Action, Screen, ScreenId};
+use crate::widgets::{draw_list, Row, ScrollList};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Section {
+    Staged,
+    Unstaged,
+    Untracked,
+}
+
+impl Section {
+    fn title(self) -> &'static str {
+        match self {
+            Section::Staged => "Staged",
+            Section::Unstaged => "Unstaged",
+            Section::Untracked => "Untracked",
+        }
+    }
+}
+
+struct Entry {
+    path: String,
+    section: Section,
+    deleted: bool,
+}
+
+/// Magit-style working-tree status: staged/unstaged/untracked files grouped under
+/// colored section headers, navigable with the arrow keys.
+pub struct StatusScreen {
+    entries: Vec<Entry>,
+    selected: usize,
+    scroll: ScrollList,
+    minibuffer: MiniBuffer,
+    theme: Theme,
+}
+
+impl StatusScreen {
+    pub fn new(config: &Config) -> Self {
+        let mut screen = Self {
+            entries: Vec::new(),
+            selected: 0,
+            scroll: ScrollList::new(),
+            minibuffer: MiniBuffer::new(config.theme),
+            theme: config.theme,
+        };
+        screen.refresh();
+        screen
+    }
+
+    /// Re-reads the working tree via `git2::Repository::statuses()` and rebuilds the
+    /// grouped entry list.
+    pub fn refresh(&mut self) {
+        self.minibuffer.clear();
+
+        let repo = match Repository::discover(".") {
+            Ok(repo) => repo,
+            Err(_) => {
+                self.entries.clear();
+                self.minibuffer
+                    .set(MessageType::Error, "Not inside a git repository");
+                return;
+            }
+        };
+
+        let mut opts = StatusOptions::new();
+        opts.include_untracked(true).recurse_untracked_dirs(true);
+
+        let statuses = match repo.statuses(Some(&mut opts)) {
+            Ok(statuses) => statuses,
+            Err(e) => {
+                self.entries.clear();
+                self.minibuffer.set(MessageType::Error, e.to_string());
+                return;
+            }
+        };
+
+        let mut entries = Vec::new();
+        for entry in statuses.iter() {
+            let Some(path) = entry.path() else { continue };
+            let flags = entry.status();
+
+            if flags.is_index_new()
+                || flags.is_index_modified()
+                || flags.is_index_deleted()
+                || flags.is_index_renamed()
+                || flags.is_index_typechange()
+            {
+                entries.push(Entry {
+                    path: path.to_string(),
+                    section: Section::Staged,
+                    deleted: false,
+                });
+            }
+
+            if flags.is_wt_modified()
+                || flags.is_wt_deleted()
+                || flags.is_wt_renamed()
+                || flags.is_wt_typechange()
+            {
+                entries.push(Entry {
+                    path: path.to_string(),
+                    section: Section::Unstaged,
+                    deleted: flags.is_wt_deleted(),
+                });
+            }
+
+            if flags.is_wt_new() {
+                entries.push(Entry {
+                    path: path.to_string(),
+                    section: Section::Untracked,
+                    deleted: false,
+                });
+            }
+        }
+
+        entries.sort_by_key(|e| match e.section {
+            Section::Staged => 0,
+            Section::Unstaged => 1,
+            Section::Untracked => 2,
+        });
+
+        self.entries = entries;
+        self.selected = if self.entries.is_empty() {
+            0
+        } else {
+            self.selected.min(self.entries.len() - 1)
+        };
+    }
+
+    /// Builds the flattened header/entry rows the scrollable list widget draws,
+    /// returning them alongside the row index `selected` currently lands on.
+    fn rows(&self) -> (Vec<Row>, usize) {
+        let mut rows = Vec::new();
+        let mut selected_row = 0;
+        let mut current_section: Option<Section> = None;
+
+        for (i, entry) in self.entries.iter().enumerate() {
+            if current_section != Some(entry.section) {
+                current_section = Some(entry.section);
+                rows.push(Row::Header(entry.section.title().to_string()));
+            }
+            if i == self.selected {
+                selected_row = rows.len();
+            }
+            rows.push(Row::Entry {
+                label: entry.path.clone(),
+                path: Some(PathBuf::from(&entry.path)),
+                color: None,
+            });
+        }
+
+        (rows, selected_row)
+    }
+
+    fn stage_selected(&mut self) {
+        let Some(entry) = self.entries.get(self.selected) else { return };
+        if entry.section == Section::Staged {
+            return;
+        }
+        if let Ok(repo) = Repository::discover(".") {
+            if let Ok(mut index) = repo.index() {
+                let path = std::path::Path::new(&entry.path);
+                let _ = if entry.deleted {
+                    index.remove_path(path)
+                } else {
+                    index.add_path(path)
+                };
+                let _ = index.write();
+            }
+        }
+        self.refresh();
+    }
+
+    fn unstage_selected(&mut self) {
+        let Some(entry) = self.entries.get(self.selected) else { return };
+        if entry.section != Section::Staged {
+            return;
+        }
+        if let Ok(repo) = Repository::discover(".") {
+            if let Ok(head) = repo.head().and_then(|h| h.peel_to_commit()) {
+                let _ = repo.reset_default(Some(head.as_object()), [&entry.path]);
+            }
+        }
+        self.refresh();
+    }
+}
+
+impl Screen for StatusScreen {
+    fn draw(&mut self, stdout: &mut Stdout, cols: u16, rows: u16) -> Result<()> {
+        stdout.execute(terminal::Clear(ClearType::All))?;
+        stdout.execute(cursor::MoveTo(0, 0))?;
+
+        if self.minibuffer.has_message() {
+            return self.minibuffer.draw(stdout, cols, rows);
+        }
+
+        if self.entries.is_empty() {
+            stdout.execute(cursor::MoveTo(0, 0))?;
+            write!(stdout, "Nothing to commit, working tree clean.")?;
+            stdout.flush()?;
+            return self.minibuffer.draw(stdout, cols, rows);
+        }
+
+        let (rendered_rows, selected_row) = self.rows();
+        let visible_rows = rows.saturating_sub(1);
+        self.scroll
+            .update_offset(selected_row, rendered_rows.len(), visible_rows as usize);
+
+        draw_list(
+            stdout,
+            &rendered_rows,
+            self.scroll.offset,
+            selected_row,
+            0,
+            visible_rows,
+            self.theme.header,
+        )
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> Action {
+        match key.code {
+            KeyCode::Char('q') => Action::SwitchTo(ScreenId::Home),
+            KeyCode::Up => {
+                self.selected = self.selected.saturating_sub(1);
+                Action::None
+            }
+            KeyCode::Down => {
+                if self.selected + 1 < self.entries.len() {
+                    self.selected += 1;
+                }
+                Action::None
+            }
+            KeyCode::Char('s') => {
+                self.stage_selected();
+                Action::None
+            }
+            KeyCode::Char('u') => {
+                self.unstage_selected();
+                Action::None
+            }
+            KeyCode::Char('g') => {
+                self.refresh();
+                Action::None
+            }
+            _ => Action::None,
+        }
+    }
+}
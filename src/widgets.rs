@@ -0,0 +1,183 @@
+use crossterm::{
+    cursor,
+    queue,
+    style::{Attribute, Color, Print, ResetColor, SetAttribute, SetForegroundColor},
+};
+use std::io::{Result, Stdout, Write};
+use std::path::{Path, PathBuf};
+
+/// A single displayed row: either a section header or a selectable entry, optionally
+/// backed by a file path that gets rendered as an OSC 8 hyperlink.
+pub enum Row {
+    Header(String),
+    Entry {
+        label: String,
+        path: Option<PathBuf>,
+        color: Option<Color>,
+    },
+}
+
+/// Tracks the scroll offset for a list taller than its viewport, keeping a few rows
+/// of context around the cursor as it moves and never scrolling past the ends.
+pub struct ScrollList {
+    pub offset: usize,
+    padding: usize,
+}
+
+impl ScrollList {
+    pub fn new() -> Self {
+        Self {
+            offset: 0,
+            padding: 3,
+        }
+    }
+
+    /// Recomputes `offset` so `selected_row` stays within `padding` rows of the
+    /// viewport edges.
+    pub fn update_offset(&mut self, selected_row: usize, len: usize, visible_rows: usize) {
+        if visible_rows == 0 || len <= visible_rows {
+            self.offset = 0;
+            return;
+        }
+
+        let max_offset = len - visible_rows;
+        if selected_row < self.offset + self.padding {
+            self.offset = selected_row.saturating_sub(self.padding);
+        } else if selected_row + self.padding + 1 > self.offset + visible_rows {
+            self.offset = selected_row + self.padding + 1 - visible_rows;
+        }
+        self.offset = self.offset.min(max_offset);
+    }
+}
+
+/// True when the terminal is known to render OSC 8 hyperlinks. VS Code's integrated
+/// terminal accepts the escape but doesn't make the link clickable, so it's excluded.
+fn supports_hyperlinks() -> bool {
+    !matches!(std::env::var("TERM_PROGRAM").as_deref(), Ok("vscode"))
+        && !matches!(std::env::var("TERM").as_deref(), Ok("dumb"))
+}
+
+/// Wraps `text` in an OSC 8 hyperlink pointing at `path`, or returns it unchanged on
+/// terminals that don't support (or mishandle) the escape sequence.
+fn hyperlink(path: &Path, text: &str) -> String {
+    if !supports_hyperlinks() {
+        return text.to_string();
+    }
+    let Ok(abs) = path.canonicalize() else {
+        // e.g. a deleted file still listed in the status screen: no absolute path to
+        // link to, so fall back to plain text rather than emitting a relative URI.
+        return text.to_string();
+    };
+    format!(
+        "\x1b]8;;file://{}\x1b\\{}\x1b]8;;\x1b\\",
+        abs.display(),
+        text
+    )
+}
+
+/// Draws the visible slice of `rows` starting at `offset`, highlighting
+/// `selected_row` and coloring headers with `header_color`. Queues all output and
+/// flushes once per frame instead of flushing per line.
+pub fn draw_list(
+    stdout: &mut Stdout,
+    rows: &[Row],
+    offset: usize,
+    selected_row: usize,
+    start_row: u16,
+    visible_rows: u16,
+    header_color: Color,
+) -> Result<()> {
+    for i in 0..visible_rows {
+        let idx = offset + i as usize;
+        let Some(row) = rows.get(idx) else { break };
+
+        queue!(stdout, cursor::MoveTo(0, start_row + i))?;
+        match row {
+            Row::Header(title) => {
+                queue!(
+                    stdout,
+                    SetForegroundColor(header_color),
+                    Print(title),
+                    ResetColor
+                )?;
+            }
+            Row::Entry { label, path, color } => {
+                let selected = idx == selected_row;
+                let text = match path {
+                    Some(path) => hyperlink(path, label),
+                    None => label.clone(),
+                };
+                if let Some(color) = color {
+                    queue!(stdout, SetForegroundColor(*color))?;
+                }
+                if selected {
+                    queue!(stdout, SetAttribute(Attribute::Reverse))?;
+                }
+                queue!(stdout, Print(format!("  {}", text)))?;
+                if selected {
+                    queue!(stdout, SetAttribute(Attribute::Reset))?;
+                }
+                if color.is_some() {
+                    queue!(stdout, ResetColor)?;
+                }
+            }
+        }
+    }
+
+    stdout.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_scroll_when_everything_fits() {
+        let mut scroll = ScrollList::new();
+        scroll.update_offset(0, 5, 10);
+        assert_eq!(scroll.offset, 0);
+
+        // Exactly filling the viewport still counts as fitting.
+        scroll.update_offset(4, 10, 10);
+        assert_eq!(scroll.offset, 0);
+    }
+
+    #[test]
+    fn no_scroll_when_viewport_has_no_rows() {
+        let mut scroll = ScrollList::new();
+        scroll.offset = 5;
+        scroll.update_offset(0, 20, 0);
+        assert_eq!(scroll.offset, 0);
+    }
+
+    #[test]
+    fn clamps_to_top_when_selection_is_near_the_start() {
+        let mut scroll = ScrollList::new();
+        scroll.offset = 4;
+        scroll.update_offset(0, 20, 5);
+        assert_eq!(scroll.offset, 0);
+    }
+
+    #[test]
+    fn clamps_to_max_offset_when_selection_is_near_the_end() {
+        let mut scroll = ScrollList::new();
+        // len=20, visible=5 -> max_offset=15; selecting the last row should never
+        // scroll further than that, even though padding would ask for more.
+        scroll.update_offset(19, 20, 5);
+        assert_eq!(scroll.offset, 15);
+    }
+
+    #[test]
+    fn keeps_padding_rows_of_context_around_the_selection() {
+        let mut scroll = ScrollList::new();
+        // offset=0, visible=10, padding=3: selecting row 6 is still within the
+        // bottom padding band (0 + 10 - 3 - 1 = 6), so no scroll is needed yet.
+        scroll.update_offset(6, 30, 10);
+        assert_eq!(scroll.offset, 0);
+
+        // Selecting row 7 crosses the padding band and scrolls by one.
+        scroll.update_offset(7, 30, 10);
+        assert_eq!(scroll.offset, 1);
+    }
+}